@@ -0,0 +1,260 @@
+//! Running battles between monsters.
+//!
+//! Where [`Species`](../species/struct.Species.html),
+//! [`Type`](../type/struct.Type.html) and
+//! [`Move`](../move/struct.Move.html) are static data describing what
+//! monsters and their moves *are*, this module is the dynamic-data
+//! layer describing a battle actually taking place: two
+//! [`Side`](enum.Side.html)s, each fielding one or more active
+//! [`Monster`](../monster/struct.Monster.html)s in
+//! [`BattleSlot`](struct.BattleSlot.html)s, acting on
+//! [`TurnChoice`](enum.TurnChoice.html)s.
+
+use crate::monster::Monster;
+#[cfg(feature = "stats")]
+use crate::stats::Stat;
+
+/// Which of the two sides in a [`Battle`](struct.Battle.html) a slot
+/// or [`Monster`](../monster/struct.Monster.html) belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    /// The left side.
+    Left,
+    /// The right side.
+    Right,
+}
+
+/// The number of simultaneously active
+/// [`Monster`](../monster/struct.Monster.html)s per side a
+/// [`Battle`](struct.Battle.html) is played with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BattleFormat {
+    /// One active monster per side.
+    Singles,
+    /// Two active monsters per side.
+    Doubles,
+    /// Three active monsters per side.
+    Triples,
+}
+
+impl BattleFormat {
+    /// The number of active slots per side in this
+    /// [`BattleFormat`](enum.BattleFormat.html).
+    pub fn active_slot_count(&self) -> usize {
+        match self {
+            BattleFormat::Singles => 1,
+            BattleFormat::Doubles => 2,
+            BattleFormat::Triples => 3,
+        }
+    }
+}
+
+/// A single active slot on a side of a [`Battle`](struct.Battle.html).
+pub struct BattleSlot {
+    /// The [`Monster`](../monster/struct.Monster.html) occupying this
+    /// slot, or `None` if it has fainted or has not yet been filled.
+    pub monster: Option<Monster>,
+}
+
+/// A choice made for a single active
+/// [`Monster`](../monster/struct.Monster.html)'s turn.
+pub enum TurnChoice {
+    /// Use a learned move against a target.
+    UseMove {
+        /// Index into the acting
+        /// [`Monster`](../monster/struct.Monster.html)'s
+        /// [`moves`](../monster/struct.Monster.html#structfield.moves)
+        /// of the [`MoveSlot`](../move/struct.MoveSlot.html) being used.
+        move_slot_index: usize,
+        /// The side the target belongs to.
+        target_side: Side,
+        /// The active slot index of the target on `target_side`.
+        target_index: usize,
+    },
+    /// Switch the active [`Monster`](../monster/struct.Monster.html)
+    /// out for another.
+    Switch {
+        /// The index, within the owning trainer's party, of the
+        /// [`Monster`](../monster/struct.Monster.html) to switch in.
+        party_index: usize,
+    },
+}
+
+/// A running battle between two sides of monsters.
+pub struct Battle {
+    /// The [`BattleFormat`](enum.BattleFormat.html) this
+    /// [`Battle`](struct.Battle.html) is played in.
+    pub format: BattleFormat,
+    /// The active slots on [`Side::Left`](enum.Side.html).
+    pub left: Vec<BattleSlot>,
+    /// The active slots on [`Side::Right`](enum.Side.html).
+    pub right: Vec<BattleSlot>,
+}
+
+impl Battle {
+    /// Create a new [`Battle`](struct.Battle.html) in the given
+    /// `format`, with every slot on both sides empty.
+    pub fn new(format: BattleFormat) -> Self {
+        let slot_count = format.active_slot_count();
+
+        Battle {
+            format,
+            left: (0..slot_count).map(|_| BattleSlot { monster: None }).collect(),
+            right: (0..slot_count).map(|_| BattleSlot { monster: None }).collect(),
+        }
+    }
+
+    /// Get the side opposite `side`.
+    pub fn get_opposite_side(&self, side: Side) -> Side {
+        match side {
+            Side::Left => Side::Right,
+            Side::Right => Side::Left,
+        }
+    }
+
+    fn slots(&self, side: Side) -> &[BattleSlot] {
+        match side {
+            Side::Left => &self.left,
+            Side::Right => &self.right,
+        }
+    }
+
+    /// Get the opposing, active monsters adjacent to `index` on `side`,
+    /// i.e. the monsters a single-target move used from that position
+    /// could legally hit.
+    ///
+    /// In [`BattleFormat::Singles`](enum.BattleFormat.html) and
+    /// [`BattleFormat::Doubles`](enum.BattleFormat.html), every active
+    /// opposing slot is adjacent. In
+    /// [`BattleFormat::Triples`](enum.BattleFormat.html), only the
+    /// opposing slot with the same index as `index` and its immediate
+    /// neighbours are adjacent: the centre slot reaches all three
+    /// opposing slots, while an end slot does not reach the far corner.
+    pub fn get_adjacent_opponents(&self, side: Side, index: usize) -> Vec<&Monster> {
+        let opposite = self.slots(self.get_opposite_side(side));
+
+        opposite.iter()
+                .enumerate()
+                .filter(|(opponent_index, _)| self.is_adjacent(index, *opponent_index))
+                .filter_map(|(_, slot)| slot.monster.as_ref())
+                .collect()
+    }
+
+    fn is_adjacent(&self, index: usize, opponent_index: usize) -> bool {
+        match self.format {
+            BattleFormat::Singles | BattleFormat::Doubles => true,
+            BattleFormat::Triples => opponent_index.abs_diff(index) <= 1,
+        }
+    }
+}
+
+#[cfg(feature = "stats")]
+/// Determine whether the monster taking `first` should act before the
+/// monster taking `second` this turn, based on their computed Speed
+/// stat.
+pub fn acts_before(first: &Monster, second: &Monster) -> bool {
+    first.computed_stat(Stat::Speed) >= second.computed_stat(Stat::Speed)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::battle::{Battle, BattleFormat, Side};
+    use crate::monster::Monster;
+    use crate::species::Species;
+    use crate::r#type::Type;
+    use phf::phf_map;
+
+    #[cfg(feature = "stats")]
+    static NEUTRAL_NATURE: crate::stats::Nature = crate::stats::Nature {
+        name: "neutral",
+        increased_stat: None,
+        decreased_stat: None,
+    };
+
+    fn make_species(types: Vec<&'static Type>) -> &'static Species<'static> {
+        Box::leak(Box::new(Species {
+            id: 0,
+            name: "test species".to_string(),
+            types,
+            #[cfg(feature = "bestiary")]
+            category: "".to_string(),
+            #[cfg(feature = "bestiary")]
+            description: "".to_string(),
+            #[cfg(feature = "bestiary")]
+            weight_in_hectograms: 0,
+            #[cfg(feature = "bestiary")]
+            height_in_decimeters: 0,
+            #[cfg(feature = "stats")]
+            base_hp: 0,
+            #[cfg(feature = "stats")]
+            base_attack: 0,
+            #[cfg(feature = "stats")]
+            base_defense: 0,
+            #[cfg(feature = "stats")]
+            base_special_attack: 0,
+            #[cfg(feature = "stats")]
+            base_special_defense: 0,
+            #[cfg(feature = "stats")]
+            base_speed: 0,
+        }))
+    }
+
+    fn make_monster(name: &'static str, species: &'static Species<'static>) -> Monster {
+        Monster {
+            name,
+            species,
+            moves: [None, None, None, None],
+            #[cfg(feature = "stats")]
+            level: 50,
+            #[cfg(feature = "stats")]
+            individual_values: Default::default(),
+            #[cfg(feature = "stats")]
+            effort_values: Default::default(),
+            #[cfg(feature = "stats")]
+            nature: &NEUTRAL_NATURE,
+        }
+    }
+
+    #[test]
+    fn test_get_opposite_side() {
+        let battle = Battle::new(BattleFormat::Singles);
+        assert_eq!(battle.get_opposite_side(Side::Left), Side::Right);
+        assert_eq!(battle.get_opposite_side(Side::Right), Side::Left);
+    }
+
+    #[test]
+    fn test_new_battle_has_one_empty_slot_per_side_in_singles() {
+        let battle = Battle::new(BattleFormat::Singles);
+        assert_eq!(battle.left.len(), 1);
+        assert_eq!(battle.right.len(), 1);
+        assert!(battle.left[0].monster.is_none());
+        assert!(battle.right[0].monster.is_none());
+    }
+
+    #[test]
+    fn test_adjacent_opponents_in_doubles_is_every_opposing_slot() {
+        static NORMAL: Type = Type {
+            name: "normal",
+            effectivenesses: phf_map! {},
+        };
+        let species = make_species(vec![&NORMAL]);
+
+        let mut battle = Battle::new(BattleFormat::Doubles);
+        battle.right[0].monster = Some(make_monster("opponent one", species));
+        battle.right[1].monster = Some(make_monster("opponent two", species));
+
+        let opponents = battle.get_adjacent_opponents(Side::Left, 0);
+        let mut opponent_names: Vec<&str> = opponents.iter().map(|monster| monster.name).collect();
+        opponent_names.sort();
+
+        assert_eq!(opponent_names, vec!["opponent one", "opponent two"]);
+    }
+
+    #[test]
+    fn test_adjacent_opponents_in_triples_excludes_far_corner() {
+        let battle = Battle::new(BattleFormat::Triples);
+        assert!(battle.is_adjacent(0, 1));
+        assert!(!battle.is_adjacent(0, 2));
+        assert!(battle.is_adjacent(1, 2));
+    }
+}
@@ -8,6 +8,11 @@
 //! individual module documentation for use of each of their respective
 //! types.
 
+pub mod battle;
 pub mod monster;
+pub mod r#move;
 pub mod species;
+#[cfg(feature = "stats")]
+pub mod stats;
+pub mod static_data;
 pub mod r#type;
@@ -7,12 +7,51 @@
 //! object. Unlike [`Species`](../species/struct.Species.html) objects,
 //! [`Monster`](struct.Monster.html) objects are meant to be mutable.
 
+use crate::r#move::{MoveSlot, MOVE_SLOT_COUNT};
 use crate::species::Species;
+#[cfg(feature = "stats")]
+use crate::stats::{EffortValues, IndividualValues, Nature, Stat};
 
 /// An individual monster.
 pub struct Monster {
     /// The [`Monster`](struct.Monster.html)'s name.
     pub name: &'static str,
     /// A reference to the [`Monster`](struct.Monster.html)'s Species.
-    pub species: &'static Species,
+    pub species: &'static Species<'static>,
+    /// The [`Monster`](struct.Monster.html)'s learned moves.
+    ///
+    /// An empty slot means no [`Move`](../move/struct.Move.html) is
+    /// learned there.
+    pub moves: [Option<MoveSlot>; MOVE_SLOT_COUNT],
+
+    #[cfg(feature = "stats")]
+    /// The [`Monster`](struct.Monster.html)'s level.
+    pub level: u8,
+    #[cfg(feature = "stats")]
+    /// The [`Monster`](struct.Monster.html)'s individual values.
+    pub individual_values: IndividualValues,
+    #[cfg(feature = "stats")]
+    /// The [`Monster`](struct.Monster.html)'s effort values.
+    pub effort_values: EffortValues,
+    #[cfg(feature = "stats")]
+    /// The [`Monster`](struct.Monster.html)'s nature.
+    pub nature: &'static Nature,
+}
+
+#[cfg(feature = "stats")]
+impl Monster {
+    /// Compute the current value of `stat` for this
+    /// [`Monster`](struct.Monster.html), from its
+    /// [`Species`](../species/struct.Species.html)' base stat, its
+    /// individual and effort values, its level and its nature.
+    pub fn computed_stat(&self, stat: Stat) -> u16 {
+        crate::stats::compute_stat(
+            stat,
+            self.species.base_stat(stat),
+            self.individual_values.get(stat),
+            self.effort_values.get(stat),
+            self.level,
+            self.nature,
+        )
+    }
 }
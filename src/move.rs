@@ -0,0 +1,148 @@
+//! Monster moves.
+//!
+//! This module defines [`Move`](struct.Move.html) objects and the
+//! [`MoveSlot`](struct.MoveSlot.html)s through which a
+//! [`Monster`](../monster/struct.Monster.html) learns them.
+//!
+//! Like [`Type`](../type/struct.Type.html), each individual
+//! [`Move`](struct.Move.html) should be defined statically to be used
+//! throughout the program. Unlike [`Move`](struct.Move.html) itself,
+//! [`MoveSlot`](struct.MoveSlot.html) is meant to be mutated, since its
+//! power points are spent as the move is used.
+//!
+//! Note that you must escape this module's name to access it. For
+//! example, the following must be written to use the
+//! [`Move`](struct.Move.html) definition:
+//!
+//! ```
+//! use monstermaker_core::r#move::Move;
+//! ```
+
+use crate::r#type::Type;
+use crate::species::Species;
+
+/// The category of a [`Move`](struct.Move.html), determining how it
+/// deals damage.
+pub enum MoveCategory {
+    /// A physical move, dealing damage based on the attacker's Attack
+    /// and the defender's Defense.
+    Physical,
+    /// A special move, dealing damage based on the attacker's Special
+    /// Attack and the defender's Special Defense.
+    Special,
+    /// A status move, dealing no direct damage.
+    Status,
+}
+
+/// A defined move.
+///
+/// [`Move`](struct.Move.html) objects are usually defined statically.
+pub struct Move {
+    /// The name of the [`Move`](struct.Move.html).
+    pub name: &'static str,
+    /// The [`Type`](../type/struct.Type.html) of the
+    /// [`Move`](struct.Move.html).
+    pub move_type: &'static Type,
+    /// The [`MoveCategory`](enum.MoveCategory.html) of the
+    /// [`Move`](struct.Move.html).
+    pub category: MoveCategory,
+    /// The base power of the [`Move`](struct.Move.html).
+    pub base_power: u8,
+    /// The accuracy of the [`Move`](struct.Move.html), out of 100.
+    pub accuracy: u8,
+    /// The maximum power points of the [`Move`](struct.Move.html).
+    pub power_points: u8,
+}
+
+impl Move {
+    /// Check the combined effectiveness of this
+    /// [`Move`](struct.Move.html)'s [`Type`](../type/struct.Type.html)
+    /// against every [`Type`](../type/struct.Type.html) defined on a
+    /// [`Species`](../species/struct.Species.html).
+    ///
+    /// See [`Type::effectiveness_against_species()`](../type/struct.Type.html#method.effectiveness_against_species).
+    pub fn effectiveness_against_species(&self, species: &Species) -> f32 {
+        self.move_type.effectiveness_against_species(species)
+    }
+}
+
+/// The number of [`MoveSlot`](struct.MoveSlot.html)s a
+/// [`Monster`](../monster/struct.Monster.html) can learn at once.
+pub const MOVE_SLOT_COUNT: usize = 4;
+
+/// A single learned [`Move`](struct.Move.html) on a
+/// [`Monster`](../monster/struct.Monster.html).
+///
+/// Power points are tracked per slot, separately from the
+/// [`Move`](struct.Move.html) definition itself, since they are spent
+/// and restored independently for each
+/// [`Monster`](../monster/struct.Monster.html) that learned the move.
+pub struct MoveSlot {
+    /// The learned [`Move`](struct.Move.html).
+    pub learned_move: &'static Move,
+    /// The power points currently remaining in this
+    /// [`MoveSlot`](struct.MoveSlot.html).
+    pub current_power_points: u8,
+    /// The maximum power points for this
+    /// [`MoveSlot`](struct.MoveSlot.html).
+    pub max_power_points: u8,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::r#move::{Move, MoveCategory};
+    use crate::species::Species;
+    use crate::r#type::Type;
+    use phf::phf_map;
+
+    #[test]
+    fn test_effectiveness_against_species_follows_move_type() {
+        static FIRE: Type = Type {
+            name: "fire",
+            effectivenesses: phf_map! {},
+        };
+        static GRASS: Type = Type {
+            name: "grass",
+            effectivenesses: phf_map! {
+                "fire" => 2.0,
+            },
+        };
+
+        static EMBER: Move = Move {
+            name: "ember",
+            move_type: &FIRE,
+            category: MoveCategory::Special,
+            base_power: 40,
+            accuracy: 100,
+            power_points: 25,
+        };
+
+        let species = Species {
+            id: 0,
+            name: "test species".to_string(),
+            types: vec![&GRASS],
+            #[cfg(feature = "bestiary")]
+            category: "".to_string(),
+            #[cfg(feature = "bestiary")]
+            description: "".to_string(),
+            #[cfg(feature = "bestiary")]
+            weight_in_hectograms: 0,
+            #[cfg(feature = "bestiary")]
+            height_in_decimeters: 0,
+            #[cfg(feature = "stats")]
+            base_hp: 0,
+            #[cfg(feature = "stats")]
+            base_attack: 0,
+            #[cfg(feature = "stats")]
+            base_defense: 0,
+            #[cfg(feature = "stats")]
+            base_special_attack: 0,
+            #[cfg(feature = "stats")]
+            base_special_defense: 0,
+            #[cfg(feature = "stats")]
+            base_speed: 0,
+        };
+
+        assert_eq!(EMBER.effectiveness_against_species(&species), 2.0);
+    }
+}
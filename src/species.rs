@@ -20,7 +20,7 @@ pub struct Species<'a> {
     
     /// A vector of the [`Species`](struct.Species.html)' 
     /// [`Types`](../type/struct.Type.html).
-    pub types: Vec<&'a Type<'a>>,
+    pub types: Vec<&'a Type>,
     
     #[cfg(feature = "bestiary")]
     /// The species' category.
@@ -34,15 +34,28 @@ pub struct Species<'a> {
     #[cfg(feature = "bestiary")]
     /// The species' height in decimeters.
     pub height_in_decimeters: u16,
-    
+
+    #[cfg(feature = "stats")]
+    /// The species' base HP stat.
+    pub base_hp: u8,
+    #[cfg(feature = "stats")]
+    /// The species' base Attack stat.
+    pub base_attack: u8,
+    #[cfg(feature = "stats")]
+    /// The species' base Defense stat.
+    pub base_defense: u8,
+    #[cfg(feature = "stats")]
+    /// The species' base Special Attack stat.
+    pub base_special_attack: u8,
+    #[cfg(feature = "stats")]
+    /// The species' base Special Defense stat.
+    pub base_special_defense: u8,
+    #[cfg(feature = "stats")]
+    /// The species' base Speed stat.
+    pub base_speed: u8,
+
     // TODO: Continue expanding the features.
     /*
-    base_hp: u8,
-    base_attack: u8,
-    base_defense: u8,
-    base_special_attack: u8,
-    base_special_defense: u8,
-    base_speed: u8,
     hp_invariant: Option<u16>,
     attack_invariant: Option<u16>,
     defense_invariant: Option<u16>,
@@ -79,4 +92,22 @@ pub struct Species<'a> {
     shape: &'a Shape,
     habitat: &'a Habitat,
     */
+}
+
+#[cfg(feature = "stats")]
+impl<'a> Species<'a> {
+    /// Look up this [`Species`](struct.Species.html)' base value for
+    /// `stat`.
+    pub fn base_stat(&self, stat: crate::stats::Stat) -> u8 {
+        use crate::stats::Stat;
+
+        match stat {
+            Stat::Hp => self.base_hp,
+            Stat::Attack => self.base_attack,
+            Stat::Defense => self.base_defense,
+            Stat::SpecialAttack => self.base_special_attack,
+            Stat::SpecialDefense => self.base_special_defense,
+            Stat::Speed => self.base_speed,
+        }
+    }
 }
\ No newline at end of file
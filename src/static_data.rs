@@ -0,0 +1,261 @@
+//! Runtime registry of static data, addressed by opaque identifiers.
+//!
+//! Where modules like [`type`](../type/index.html) and
+//! [`species`](../species/index.html) expect their objects to be wired
+//! up by hand as `'static` references (often backed by compile-time
+//! [`phf::Map`](https://docs.rs/phf/0.8.0/phf/struct.Map.html)s), this
+//! module supports building the same kind of data at runtime, e.g. from
+//! a file loaded on startup. Data is looked up through small, cheap
+//! identifiers rather than names or references, and
+//! [`StaticData`](struct.StaticData.html) is the central registry that
+//! owns the libraries built this way.
+
+use std::collections::HashMap;
+
+/// An opaque identifier referring to a [`Type`](../type/struct.Type.html)
+/// registered in a [`TypeLibrary`](struct.TypeLibrary.html).
+///
+/// [`TypeIdentifier`](struct.TypeIdentifier.html) objects are cheap to
+/// copy and compare, and are meant to be used in place of
+/// [`Type`](../type/struct.Type.html) references or names wherever a
+/// [`TypeLibrary`](struct.TypeLibrary.html) is available.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TypeIdentifier(u8);
+
+impl From<u8> for TypeIdentifier {
+    fn from(value: u8) -> Self {
+        TypeIdentifier(value)
+    }
+}
+
+impl From<TypeIdentifier> for u8 {
+    fn from(value: TypeIdentifier) -> Self {
+        value.0
+    }
+}
+
+/// A runtime-built library of type names and their effectivenesses
+/// against each other.
+///
+/// Unlike [`Type`](../type/struct.Type.html), which resolves
+/// effectiveness by hashing a name in a
+/// [`phf::Map`](https://docs.rs/phf/0.8.0/phf/struct.Map.html),
+/// [`TypeLibrary`](struct.TypeLibrary.html) resolves effectiveness
+/// through O(1) indexing into a matrix keyed by
+/// [`TypeIdentifier`](struct.TypeIdentifier.html). Build one with a
+/// [`TypeLibraryBuilder`](struct.TypeLibraryBuilder.html).
+pub struct TypeLibrary {
+    names: Vec<String>,
+    ids_by_name: HashMap<String, TypeIdentifier>,
+    effectivenesses: Vec<Vec<f32>>,
+}
+
+impl TypeLibrary {
+    /// Look up the [`TypeIdentifier`](struct.TypeIdentifier.html)
+    /// registered for `name`.
+    pub fn get_type_id(&self, name: &str) -> Option<TypeIdentifier> {
+        self.ids_by_name.get(name).copied()
+    }
+
+    /// Look up the name registered for `id`.
+    pub fn get_type_name(&self, id: TypeIdentifier) -> Option<&str> {
+        self.names.get(id.0 as usize).map(String::as_str)
+    }
+
+    /// Get the effectiveness of `attacking` against `defending`.
+    ///
+    /// If either identifier was not registered in this
+    /// [`TypeLibrary`](struct.TypeLibrary.html), the default value of
+    /// `1.0` is returned.
+    pub fn get_single_effectiveness(&self, attacking: TypeIdentifier, defending: TypeIdentifier) -> f32 {
+        self.effectivenesses
+            .get(attacking.0 as usize)
+            .and_then(|row| row.get(defending.0 as usize))
+            .copied()
+            .unwrap_or(1.0)
+    }
+}
+
+/// Builder for a [`TypeLibrary`](struct.TypeLibrary.html).
+///
+/// Types are registered one at a time with
+/// [`add_type()`](#method.add_type), growing the effectiveness matrix
+/// as it goes. Any cell that is not explicitly set with
+/// [`set_effectiveness()`](#method.set_effectiveness) defaults to
+/// `1.0`.
+pub struct TypeLibraryBuilder {
+    names: Vec<String>,
+    effectivenesses: Vec<Vec<f32>>,
+}
+
+impl TypeLibraryBuilder {
+    /// Create a new, empty builder.
+    pub fn new() -> Self {
+        TypeLibraryBuilder {
+            names: Vec::new(),
+            effectivenesses: Vec::new(),
+        }
+    }
+
+    /// Register a new type under `name`, returning the
+    /// [`TypeIdentifier`](struct.TypeIdentifier.html) it was assigned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if 256 types have already been registered, since
+    /// [`TypeIdentifier`](struct.TypeIdentifier.html) cannot represent
+    /// any more distinct ids.
+    pub fn add_type(&mut self, name: &str) -> TypeIdentifier {
+        assert!(self.names.len() < 256, "cannot register more than 256 types");
+
+        let id = TypeIdentifier(self.names.len() as u8);
+        self.names.push(name.to_string());
+
+        for row in self.effectivenesses.iter_mut() {
+            row.push(1.0);
+        }
+        self.effectivenesses.push(vec![1.0; self.names.len()]);
+
+        id
+    }
+
+    /// Set the effectiveness of `attacking` against `defending`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either `attacking` or `defending` was not registered
+    /// with [`add_type()`](#method.add_type).
+    pub fn set_effectiveness(&mut self, attacking: TypeIdentifier, defending: TypeIdentifier, effectiveness: f32) {
+        let type_count = self.names.len();
+        assert!(
+            (attacking.0 as usize) < type_count && (defending.0 as usize) < type_count,
+            "type identifier is not registered with this builder"
+        );
+
+        self.effectivenesses[attacking.0 as usize][defending.0 as usize] = effectiveness;
+    }
+
+    /// Build the final [`TypeLibrary`](struct.TypeLibrary.html).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the effectiveness matrix is not square, i.e. it does
+    /// not have exactly as many rows and columns as registered types.
+    pub fn build(self) -> TypeLibrary {
+        let type_count = self.names.len();
+        assert!(
+            self.effectivenesses.len() == type_count
+                && self.effectivenesses.iter().all(|row| row.len() == type_count),
+            "effectiveness matrix must be square with one row/column per registered type"
+        );
+
+        let ids_by_name = self.names
+                              .iter()
+                              .enumerate()
+                              .map(|(index, name)| (name.clone(), TypeIdentifier(index as u8)))
+                              .collect();
+
+        TypeLibrary {
+            names: self.names,
+            ids_by_name,
+            effectivenesses: self.effectivenesses,
+        }
+    }
+}
+
+impl Default for TypeLibraryBuilder {
+    fn default() -> Self {
+        TypeLibraryBuilder::new()
+    }
+}
+
+/// Central registry of the static data libraries used by a running
+/// program.
+///
+/// [`StaticData`](struct.StaticData.html) is the runtime counterpart to
+/// the compile-time, by-hand wiring used elsewhere in the crate: rather
+/// than defining `'static` [`Type`](../type/struct.Type.html) objects
+/// ahead of time, a program can load its type data at startup and
+/// register it here.
+///
+/// Only type data is registered: [`StaticData`](struct.StaticData.html)
+/// holds a single [`TypeLibrary`](struct.TypeLibrary.html), and
+/// [`Species`](../species/struct.Species.html) objects are not held
+/// here and are still defined by hand.
+pub struct StaticData {
+    /// The registered [`TypeLibrary`](struct.TypeLibrary.html).
+    pub type_library: TypeLibrary,
+}
+
+impl StaticData {
+    /// Create a new registry around an already-built
+    /// [`TypeLibrary`](struct.TypeLibrary.html).
+    pub fn new(type_library: TypeLibrary) -> Self {
+        StaticData { type_library }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::static_data::{TypeIdentifier, TypeLibraryBuilder};
+
+    #[test]
+    fn test_identifier_roundtrips_through_u8() {
+        let id = TypeIdentifier::from(3u8);
+        assert_eq!(u8::from(id), 3u8);
+    }
+
+    #[test]
+    fn test_unspecified_effectiveness_defaults_to_neutral() {
+        let mut builder = TypeLibraryBuilder::new();
+        let fire = builder.add_type("fire");
+        let water = builder.add_type("water");
+        let library = builder.build();
+
+        assert_eq!(library.get_single_effectiveness(fire, water), 1.0);
+    }
+
+    #[test]
+    fn test_set_effectiveness_is_looked_up_by_id() {
+        let mut builder = TypeLibraryBuilder::new();
+        let fire = builder.add_type("fire");
+        let water = builder.add_type("water");
+        builder.set_effectiveness(water, fire, 2.0);
+        let library = builder.build();
+
+        assert_eq!(library.get_single_effectiveness(water, fire), 2.0);
+        assert_eq!(library.get_single_effectiveness(fire, water), 1.0);
+    }
+
+    #[test]
+    fn test_get_type_id_and_name_are_consistent() {
+        let mut builder = TypeLibraryBuilder::new();
+        let fire = builder.add_type("fire");
+        let library = builder.build();
+
+        assert_eq!(library.get_type_id("fire"), Some(fire));
+        assert_eq!(library.get_type_name(fire), Some("fire"));
+        assert_eq!(library.get_type_id("water"), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "not registered")]
+    fn test_set_effectiveness_panics_on_unregistered_identifier() {
+        let mut builder = TypeLibraryBuilder::new();
+        let fire = builder.add_type("fire");
+        let unregistered = TypeIdentifier::from(1u8);
+
+        builder.set_effectiveness(fire, unregistered, 2.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "256 types")]
+    fn test_add_type_panics_past_256_types() {
+        let mut builder = TypeLibraryBuilder::new();
+        for i in 0..256 {
+            builder.add_type(&format!("type {}", i));
+        }
+
+        builder.add_type("one type too many");
+    }
+}
@@ -0,0 +1,176 @@
+//! Computed stats for individual monsters.
+//!
+//! [`Species`](../species/struct.Species.html) objects only define base
+//! stats shared by every [`Monster`](../monster/struct.Monster.html) of
+//! that species. Each individual [`Monster`](../monster/struct.Monster.html)
+//! additionally carries its own [`IndividualValues`](type.IndividualValues.html),
+//! [`EffortValues`](type.EffortValues.html), level and
+//! [`Nature`](struct.Nature.html), which together determine its actual,
+//! computed stats through [`Monster::computed_stat()`](../monster/struct.Monster.html#method.computed_stat).
+
+/// One of the six stats a [`Species`](../species/struct.Species.html)
+/// and [`Monster`](../monster/struct.Monster.html) define.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stat {
+    /// Hit points.
+    Hp,
+    /// Physical attack.
+    Attack,
+    /// Physical defense.
+    Defense,
+    /// Special attack.
+    SpecialAttack,
+    /// Special defense.
+    SpecialDefense,
+    /// Speed.
+    Speed,
+}
+
+/// A value defined per [`Stat`](enum.Stat.html).
+///
+/// Used both for [`IndividualValues`](type.IndividualValues.html) and
+/// [`EffortValues`](type.EffortValues.html), which share the same
+/// shape.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StatSet<T> {
+    /// The value for [`Stat::Hp`](enum.Stat.html).
+    pub hp: T,
+    /// The value for [`Stat::Attack`](enum.Stat.html).
+    pub attack: T,
+    /// The value for [`Stat::Defense`](enum.Stat.html).
+    pub defense: T,
+    /// The value for [`Stat::SpecialAttack`](enum.Stat.html).
+    pub special_attack: T,
+    /// The value for [`Stat::SpecialDefense`](enum.Stat.html).
+    pub special_defense: T,
+    /// The value for [`Stat::Speed`](enum.Stat.html).
+    pub speed: T,
+}
+
+impl<T: Copy> StatSet<T> {
+    /// Get the value for `stat`.
+    pub fn get(&self, stat: Stat) -> T {
+        match stat {
+            Stat::Hp => self.hp,
+            Stat::Attack => self.attack,
+            Stat::Defense => self.defense,
+            Stat::SpecialAttack => self.special_attack,
+            Stat::SpecialDefense => self.special_defense,
+            Stat::Speed => self.speed,
+        }
+    }
+}
+
+/// The individual values (sometimes called IVs) of a
+/// [`Monster`](../monster/struct.Monster.html), one per
+/// [`Stat`](enum.Stat.html).
+pub type IndividualValues = StatSet<u8>;
+
+/// The effort values (sometimes called EVs) of a
+/// [`Monster`](../monster/struct.Monster.html), one per
+/// [`Stat`](enum.Stat.html).
+pub type EffortValues = StatSet<u8>;
+
+/// A monster's nature, raising one [`Stat`](enum.Stat.html) and
+/// lowering another.
+///
+/// A neutral nature has no increased or decreased
+/// [`Stat`](enum.Stat.html) and multiplies every stat by `1.0`.
+/// [`Stat::Hp`](enum.Stat.html) is never affected by nature, regardless
+/// of what is configured here.
+pub struct Nature {
+    /// The name of the [`Nature`](struct.Nature.html).
+    pub name: &'static str,
+    /// The [`Stat`](enum.Stat.html) this [`Nature`](struct.Nature.html)
+    /// raises by a factor of `1.1`, if any.
+    pub increased_stat: Option<Stat>,
+    /// The [`Stat`](enum.Stat.html) this [`Nature`](struct.Nature.html)
+    /// lowers by a factor of `0.9`, if any.
+    pub decreased_stat: Option<Stat>,
+}
+
+impl Nature {
+    /// Get the multiplier this [`Nature`](struct.Nature.html) applies
+    /// to `stat`.
+    pub fn multiplier_for(&self, stat: Stat) -> f32 {
+        if stat == Stat::Hp {
+            return 1.0;
+        }
+
+        if self.increased_stat == Some(stat) {
+            1.1
+        } else if self.decreased_stat == Some(stat) {
+            0.9
+        } else {
+            1.0
+        }
+    }
+}
+
+/// Compute the value of `stat` for a monster with the given base stat,
+/// individual value, effort value, level and nature.
+///
+/// Implements the standard derived-stat formula: for
+/// [`Stat::Hp`](enum.Stat.html), `floor((2*base + iv + floor(ev/4)) *
+/// level / 100) + level + 10`; for every other stat, `floor((floor((2*base
+/// + iv + floor(ev/4)) * level / 100) + 5) * nature_multiplier)`.
+pub fn compute_stat(stat: Stat, base: u8, individual_value: u8, effort_value: u8, level: u8, nature: &Nature) -> u16 {
+    let base = base as u32;
+    let individual_value = individual_value as u32;
+    let effort_value = effort_value as u32;
+    let level = level as u32;
+
+    let core = (2 * base + individual_value + effort_value / 4) * level / 100;
+
+    match stat {
+        Stat::Hp => (core + level + 10) as u16,
+        _ => (((core + 5) as f32) * nature.multiplier_for(stat)).floor() as u16,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::stats::{compute_stat, Nature, Stat};
+
+    fn neutral_nature() -> Nature {
+        Nature {
+            name: "neutral",
+            increased_stat: None,
+            decreased_stat: None,
+        }
+    }
+
+    #[test]
+    fn test_computed_hp_ignores_nature() {
+        let nature = Nature {
+            name: "quirky but irrelevant to hp",
+            increased_stat: Some(Stat::Attack),
+            decreased_stat: Some(Stat::Defense),
+        };
+
+        let hp = compute_stat(Stat::Hp, 100, 31, 0, 100, &nature);
+        assert_eq!(hp, 2 * 100 * 100 / 100 + 31 * 100 / 100 + 100 + 10);
+    }
+
+    #[test]
+    fn test_computed_stat_with_neutral_nature() {
+        let attack = compute_stat(Stat::Attack, 100, 31, 0, 100, &neutral_nature());
+        assert_eq!(attack, (2 * 100 + 31) + 5);
+    }
+
+    #[test]
+    fn test_computed_stat_applies_nature_multiplier() {
+        let nature = Nature {
+            name: "adamant",
+            increased_stat: Some(Stat::Attack),
+            decreased_stat: Some(Stat::SpecialAttack),
+        };
+
+        let base_core = 2 * 100 + 31;
+        let attack = compute_stat(Stat::Attack, 100, 31, 0, 100, &nature);
+        let special_attack = compute_stat(Stat::SpecialAttack, 100, 31, 0, 100, &nature);
+
+        assert_eq!(attack, (((base_core + 5) as f32) * 1.1).floor() as u16);
+        assert_eq!(special_attack, (((base_core + 5) as f32) * 0.9).floor() as u16);
+    }
+}
@@ -17,6 +17,8 @@
 
 use phf::Map;
 
+use crate::species::Species;
+
 /// A defined type.
 ///
 /// [`Type`](struct.Type.html) objects are usually defined statically.
@@ -106,17 +108,33 @@ impl Type {
     /// If no effectiveness has been defined, a default value of `1.0`
     /// is returned.
     pub fn effectiveness_of_type(&self, other: &Type) -> f32 {
-        self.effectivenesses.get(&other.name)
-                            .unwrap_or(&1.0)
-                            .clone()
+        *self.effectivenesses.get(&other.name)
+                             .unwrap_or(&1.0)
+    }
+
+    /// Check the combined effectiveness of this [`Type`](struct.Type.html)
+    /// against every [`Type`](struct.Type.html) defined on a
+    /// [`Species`](../species/struct.Species.html).
+    ///
+    /// Each of the [`Species`](../species/struct.Species.html)' types
+    /// contributes this [`Type`](struct.Type.html)'s effectiveness
+    /// against it, and the contributions are multiplied together. A [`Species`](../species/struct.Species.html) with no
+    /// types defined is neutral, returning `1.0`.
+    pub fn effectiveness_against_species(&self, species: &Species) -> f32 {
+        species.types
+               .iter()
+               .fold(1.0, |accumulator, defending_type| {
+                   accumulator * defending_type.effectiveness_of_type(self)
+               })
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::r#type::Type;
+    use crate::species::Species;
     use phf::phf_map;
-    
+
     #[test]
     fn test_returns_effectiveness() {
         static TYPE1: Type = Type {
@@ -163,4 +181,69 @@ mod tests {
         
         assert_eq!(ONLY_TYPE.effectiveness_of_type(&ONLY_TYPE), 2.0);
     }
+
+    fn make_species(types: Vec<&Type>) -> Species<'_> {
+        Species {
+            id: 0,
+            name: "test species".to_string(),
+            types,
+            #[cfg(feature = "bestiary")]
+            category: "".to_string(),
+            #[cfg(feature = "bestiary")]
+            description: "".to_string(),
+            #[cfg(feature = "bestiary")]
+            weight_in_hectograms: 0,
+            #[cfg(feature = "bestiary")]
+            height_in_decimeters: 0,
+            #[cfg(feature = "stats")]
+            base_hp: 0,
+            #[cfg(feature = "stats")]
+            base_attack: 0,
+            #[cfg(feature = "stats")]
+            base_defense: 0,
+            #[cfg(feature = "stats")]
+            base_special_attack: 0,
+            #[cfg(feature = "stats")]
+            base_special_defense: 0,
+            #[cfg(feature = "stats")]
+            base_speed: 0,
+        }
+    }
+
+    #[test]
+    fn test_effectiveness_against_species_multiplies_each_type() {
+        static ATTACKER: Type = Type {
+            name: "attacker",
+            effectivenesses: phf_map! {},
+        };
+        static STRONG_AGAINST: Type = Type {
+            name: "strong against",
+            effectivenesses: phf_map! {
+                "attacker" => 2.0,
+            },
+        };
+        static WEAK_AGAINST: Type = Type {
+            name: "weak against",
+            effectivenesses: phf_map! {
+                "attacker" => 0.5,
+            },
+        };
+
+        let mixed = make_species(vec![&STRONG_AGAINST, &WEAK_AGAINST]);
+        assert_eq!(ATTACKER.effectiveness_against_species(&mixed), 1.0);
+
+        let doubly_strong = make_species(vec![&STRONG_AGAINST, &STRONG_AGAINST]);
+        assert_eq!(ATTACKER.effectiveness_against_species(&doubly_strong), 4.0);
+    }
+
+    #[test]
+    fn test_effectiveness_against_species_with_no_types_is_neutral() {
+        static ATTACKER: Type = Type {
+            name: "attacker",
+            effectivenesses: phf_map! {},
+        };
+
+        let typeless = make_species(vec![]);
+        assert_eq!(ATTACKER.effectiveness_against_species(&typeless), 1.0);
+    }
 }